@@ -3,7 +3,7 @@ use nu_protocol::ast::{Call, CellPath, PathMember};
 use nu_protocol::engine::{Closure, Command, EngineState, Stack};
 use nu_protocol::{
     record, Category, Example, FromValue, IntoInterruptiblePipelineData, IntoPipelineData,
-    PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+    PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
@@ -34,6 +34,23 @@ impl Command for Insert {
                 SyntaxShape::Any,
                 "the new value to give the cell(s)",
             )
+            .named(
+                "before",
+                SyntaxShape::String,
+                "place the new column immediately before this existing column",
+                None,
+            )
+            .named(
+                "after",
+                SyntaxShape::String,
+                "place the new column immediately after this existing column",
+                None,
+            )
+            .switch(
+                "strict",
+                "error if an integer cell path index is out of range, instead of padding with nulls",
+                None,
+            )
             .allow_variants_without_examples(true)
             .category(Category::Filters)
     }
@@ -99,10 +116,166 @@ impl Command for Insert {
                     }),
                 ],
             )),
+        },
+        Example {
+            description: "Insert a column next to an existing one instead of at the end",
+            example: "{name: 'nu', stars: 5} | insert priority 1 --after name",
+            result: Some(Value::test_record(record! {
+                "name" =>     Value::test_string("nu"),
+                "priority" => Value::test_int(1),
+                "stars" =>    Value::test_int(5),
+            })),
         }]
     }
 }
 
+enum AnchorSide {
+    Before,
+    After,
+}
+
+struct Anchor {
+    column: String,
+    side: AnchorSide,
+}
+
+fn parse_anchor(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    cell_path: &CellPath,
+) -> Result<Option<Anchor>, ShellError> {
+    let before: Option<Spanned<String>> = call.get_flag(engine_state, stack, "before")?;
+    let after: Option<Spanned<String>> = call.get_flag(engine_state, stack, "after")?;
+
+    let anchor = match (before, after) {
+        (Some(before), Some(after)) => {
+            return Err(ShellError::GenericError(
+                "`--before` and `--after` cannot be used together".into(),
+                "pick only one".into(),
+                Some(after.span),
+                None,
+                vec![ShellError::GenericError(
+                    "`--before` given here".into(),
+                    "conflicting flag".into(),
+                    Some(before.span),
+                    None,
+                    Vec::new(),
+                )],
+            ));
+        }
+        (Some(before), None) => Some(Anchor {
+            column: before.item,
+            side: AnchorSide::Before,
+        }),
+        (None, Some(after)) => Some(Anchor {
+            column: after.item,
+            side: AnchorSide::After,
+        }),
+        (None, None) => None,
+    };
+
+    let is_single_named_member = cell_path.members.len() == 1
+        && matches!(cell_path.members.first(), Some(PathMember::String { .. }));
+
+    if anchor.is_some() && !is_single_named_member {
+        return Err(ShellError::GenericError(
+            "`--before`/`--after` require a single column name".into(),
+            "only valid when inserting a single named column, not a nested cell path".into(),
+            Some(call.head),
+            None,
+            Vec::new(),
+        ));
+    }
+
+    Ok(anchor)
+}
+
+fn insert_at_cell_path(
+    input: &mut Value,
+    cell_path: &CellPath,
+    replacement: Value,
+    anchor: Option<&Anchor>,
+    span: Span,
+) -> Result<(), ShellError> {
+    let Some(anchor) = anchor else {
+        return input.insert_data_at_cell_path(&cell_path.members, replacement, span);
+    };
+
+    // `parse_anchor` only ever returns `Some` once it has confirmed `cell_path` is a
+    // single string member, so this is the column actually being inserted.
+    let Some(PathMember::String { val: key, .. }) = cell_path.members.first() else {
+        unreachable!("parse_anchor guarantees a single string member when anchor is Some");
+    };
+
+    let record = input.as_record()?;
+
+    if record.columns().any(|col| col == key) {
+        return Err(ShellError::GenericError(
+            format!("Column '{key}' already exists"),
+            "column already exists".into(),
+            Some(span),
+            None,
+            Vec::new(),
+        ));
+    }
+
+    let anchor_idx = record
+        .columns()
+        .position(|col| col == anchor.column.as_str())
+        .ok_or_else(|| {
+            ShellError::GenericError(
+                format!("Cannot find column '{}' to insert relative to", anchor.column),
+                "no such column".into(),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+    let insert_at = match anchor.side {
+        AnchorSide::Before => anchor_idx,
+        AnchorSide::After => anchor_idx + 1,
+    };
+
+    let mut entries: Vec<(String, Value)> = record
+        .columns()
+        .cloned()
+        .zip(record.values().cloned())
+        .collect();
+    entries.insert(insert_at, (key.clone(), replacement));
+
+    *input = Value::record(entries.into_iter().collect(), span);
+    Ok(())
+}
+
+/// Pulls `val` elements off of `input`, padding the gap with `Value::nothing` unless
+/// `strict` is set, in which case running out of elements is an out-of-range error.
+fn pad_to_index(
+    input: &mut impl Iterator<Item = Value>,
+    val: usize,
+    strict: bool,
+    span: Span,
+) -> Result<Vec<Value>, ShellError> {
+    let mut pre_elems = vec![];
+
+    for _ in 0..val {
+        if let Some(v) = input.next() {
+            pre_elems.push(v);
+        } else if strict {
+            return Err(ShellError::IndexOutOfRange {
+                index: val,
+                size: pre_elems.len(),
+                span,
+            });
+        } else {
+            pre_elems.push(Value::nothing(span))
+        }
+    }
+
+    Ok(pre_elems)
+}
+
 fn insert(
     engine_state: &EngineState,
     stack: &mut Stack,
@@ -114,6 +287,8 @@ fn insert(
 
     let cell_path: CellPath = call.req(engine_state, stack, 0)?;
     let replacement: Value = call.req(engine_state, stack, 1)?;
+    let anchor = parse_anchor(engine_state, stack, call, &cell_path)?;
+    let strict = call.has_flag(engine_state, stack, "strict")?;
 
     let redirect_stdout = call.redirect_stdout;
     let redirect_stderr = call.redirect_stderr;
@@ -157,9 +332,11 @@ fn insert(
                     match output {
                         Ok(pd) => {
                             let span = pd.span().unwrap_or(span);
-                            if let Err(e) = input.insert_data_at_cell_path(
-                                &cell_path.members,
+                            if let Err(e) = insert_at_cell_path(
+                                &mut input,
+                                &cell_path,
                                 pd.into_value(span),
+                                anchor.as_ref(),
                                 span,
                             ) {
                                 return Value::error(e, span);
@@ -174,32 +351,30 @@ fn insert(
             )
             .map(|x| x.set_metadata(metadata))
     } else {
-        if let Some(PathMember::Int { val, .. }) = cell_path.members.first() {
-            let mut input = input.into_iter();
-            let mut pre_elems = vec![];
-
-            for _ in 0..*val {
-                if let Some(v) = input.next() {
-                    pre_elems.push(v);
-                } else {
-                    pre_elems.push(Value::nothing(span))
-                }
-            }
+        if anchor.is_none() {
+            if let Some(PathMember::Int { val, .. }) = cell_path.members.first() {
+                let mut input = input.into_iter();
+                let pre_elems = pad_to_index(&mut input, *val, strict, span)?;
 
-            return Ok(pre_elems
-                .into_iter()
-                .chain(vec![replacement])
-                .chain(input)
-                .into_pipeline_data_with_metadata(metadata, ctrlc));
+                return Ok(pre_elems
+                    .into_iter()
+                    .chain(vec![replacement])
+                    .chain(input)
+                    .into_pipeline_data_with_metadata(metadata, ctrlc));
+            }
         }
         input
             .map(
                 move |mut input| {
                     let replacement = replacement.clone();
 
-                    if let Err(e) =
-                        input.insert_data_at_cell_path(&cell_path.members, replacement, span)
-                    {
+                    if let Err(e) = insert_at_cell_path(
+                        &mut input,
+                        &cell_path,
+                        replacement,
+                        anchor.as_ref(),
+                        span,
+                    ) {
                         return Value::error(e, span);
                     }
 
@@ -221,4 +396,32 @@ mod test {
 
         test_examples(Insert {})
     }
+
+    #[test]
+    fn strict_errors_on_out_of_range_index() {
+        let span = Span::test_data();
+        let mut input = vec![Value::test_int(1), Value::test_int(2)].into_iter();
+
+        let err = pad_to_index(&mut input, 5, true, span).expect_err("should be out of range");
+        assert!(matches!(
+            err,
+            ShellError::IndexOutOfRange {
+                index: 5,
+                size: 2,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn non_strict_pads_with_nothing() {
+        let span = Span::test_data();
+        let mut input = vec![Value::test_int(1)].into_iter();
+
+        let pre_elems = pad_to_index(&mut input, 3, false, span).expect("should not error");
+        assert_eq!(pre_elems.len(), 3);
+        assert_eq!(pre_elems[0], Value::test_int(1));
+        assert!(pre_elems[1].is_nothing());
+        assert!(pre_elems[2].is_nothing());
+    }
 }
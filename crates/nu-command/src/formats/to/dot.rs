@@ -0,0 +1,194 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone, Copy)]
+enum GraphKind {
+    Directed,
+    Undirected,
+}
+
+impl GraphKind {
+    fn keyword(self) -> &'static str {
+        match self {
+            GraphKind::Directed => "digraph",
+            GraphKind::Undirected => "graph",
+        }
+    }
+
+    fn edge_op(self) -> &'static str {
+        match self {
+            GraphKind::Directed => "->",
+            GraphKind::Undirected => "--",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ToDot;
+
+impl Command for ToDot {
+    fn name(&self) -> &str {
+        "to dot"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to dot")
+            .input_output_types(vec![(Type::Table(vec![]), Type::String)])
+            .named(
+                "from",
+                SyntaxShape::String,
+                "column to use as the edge source (defaults to 'from')",
+                None,
+            )
+            .named(
+                "to",
+                SyntaxShape::String,
+                "column to use as the edge target (defaults to 'to')",
+                None,
+            )
+            .switch(
+                "undirected",
+                "emit an undirected graph (`graph` with `--` edges) instead of a directed one",
+                None,
+            )
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert a table of edges into Graphviz DOT text."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["graphviz", "graph", "edges"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let from_col: Option<String> = call.get_flag(engine_state, stack, "from")?;
+        let to_col: Option<String> = call.get_flag(engine_state, stack, "to")?;
+        let undirected = call.has_flag(engine_state, stack, "undirected")?;
+
+        let from_col = from_col.unwrap_or_else(|| "from".to_string());
+        let to_col = to_col.unwrap_or_else(|| "to".to_string());
+        let kind = if undirected {
+            GraphKind::Undirected
+        } else {
+            GraphKind::Directed
+        };
+
+        let config = stack.get_config(engine_state);
+        let value = input.into_value(span);
+        let rows = value.as_list()?;
+
+        let mut dot = format!("{} {{\n", kind.keyword());
+        for row in rows {
+            let record = row.as_record()?;
+
+            let from = record
+                .get(from_col.as_str())
+                .ok_or_else(|| ShellError::CantFindColumn {
+                    col_name: from_col.clone(),
+                    span,
+                    src_span: row.span(),
+                })?
+                .clone()
+                .into_string(", ", &config);
+            let to = record
+                .get(to_col.as_str())
+                .ok_or_else(|| ShellError::CantFindColumn {
+                    col_name: to_col.clone(),
+                    span,
+                    src_span: row.span(),
+                })?
+                .clone()
+                .into_string(", ", &config);
+
+            let mut attrs = Vec::new();
+            if let Some(label) = record.get("label") {
+                attrs.push(format!(
+                    "label=\"{}\"",
+                    escape_dot_string(&label.clone().into_string(", ", &config))
+                ));
+            }
+            if let Some(weight) = record.get("weight") {
+                attrs.push(format!(
+                    "weight={}",
+                    weight.clone().into_string(", ", &config)
+                ));
+            }
+
+            let attrs = if attrs.is_empty() {
+                String::new()
+            } else {
+                format!(" [{}]", attrs.join(", "))
+            };
+
+            dot.push_str(&format!(
+                "    {} {} {}{};\n",
+                quote_id(&from),
+                kind.edge_op(),
+                quote_id(&to),
+                attrs
+            ));
+        }
+        dot.push_str("}\n");
+
+        Ok(Value::string(dot, span).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Render a dependency table as a directed Graphviz graph",
+                example: "[[from, to]; [a, b] [b, c]] | to dot",
+                result: Some(Value::test_string(
+                    "digraph {\n    \"a\" -> \"b\";\n    \"b\" -> \"c\";\n}\n",
+                )),
+            },
+            Example {
+                description: "Render an edge table as an undirected graph",
+                example: "[[from, to]; [a, b]] | to dot --undirected",
+                result: Some(Value::test_string("graph {\n    \"a\" -- \"b\";\n}\n")),
+            },
+            Example {
+                description: "Labels containing quotes are escaped",
+                example: r#"[[from, to, label]; [a, b, 'say "hi"']] | to dot"#,
+                result: Some(Value::test_string(
+                    "digraph {\n    \"a\" -> \"b\" [label=\"say \\\"hi\\\"\"];\n}\n",
+                )),
+            },
+        ]
+    }
+}
+
+fn escape_dot_string(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn quote_id(value: &str) -> String {
+    format!("\"{}\"", escape_dot_string(value))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ToDot {})
+    }
+}
@@ -0,0 +1,152 @@
+use crate::math::reducers::{reducer_for, Reduce};
+use crate::math::utils::run_with_function;
+use nu_engine::{eval_block, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
+use nu_protocol::{
+    record, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+use std::cmp::Ordering;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math max"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math max")
+            .input_output_types(vec![
+                (Type::List(Box::new(Type::Any)), Type::Any),
+                (Type::Table(vec![]), Type::Record(vec![])),
+            ])
+            .named(
+                "by",
+                SyntaxShape::Closure(None),
+                "find the element whose closure result is the largest, rather than comparing elements directly",
+                None,
+            )
+            .allow_variants_without_examples(true)
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Finds the maximum within a list of values or tables."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["maximum", "largest", "biggest"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        if let Some(closure) = call.get_flag(engine_state, stack, "by")? {
+            return find_max_by(engine_state, stack, call, input, closure);
+        }
+        run_with_function(call, input, maximum)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Compute the maximum of a list of numbers",
+                example: "[-50 100 25] | math max",
+                result: Some(Value::test_int(100)),
+            },
+            Example {
+                description: "Compute the maxima of the columns of a table",
+                example: "[{a: 1 b: 3} {a: 2 b: -1}] | math max",
+                result: Some(Value::test_record(record! {
+                    "a" => Value::test_int(2),
+                    "b" => Value::test_int(3),
+                })),
+            },
+            Example {
+                description: "Find the maximum of a list of arbitrary values (Warning: Weird)",
+                example: "[-50 'hello' true] | math max",
+                result: Some(Value::test_string("hello")),
+            },
+            Example {
+                description: "Find the row whose size is largest",
+                example: "ls | math max --by {|f| $f.size }",
+                result: None,
+            },
+        ]
+    }
+}
+
+pub fn maximum(values: &[Value], span: Span, head: Span) -> Result<Value, ShellError> {
+    let max_func = reducer_for(Reduce::Maximum);
+    max_func(Value::nothing(head), values.to_vec(), span, head)
+}
+
+fn find_max_by(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+    closure: Closure,
+) -> Result<PipelineData, ShellError> {
+    let span = call.head;
+    let block = engine_state.get_block(closure.block_id).clone();
+    let mut stack = stack.captures_to_stack(closure.captures);
+    let orig_env_vars = stack.env_vars.clone();
+    let orig_env_hidden = stack.env_hidden.clone();
+    let var_id = block.signature.get_positional(0).and_then(|var| var.var_id);
+
+    let mut best: Option<(Value, Value)> = None;
+    for element in input.into_iter() {
+        // with_env() is used here to ensure that each iteration uses
+        // a different set of environment variables.
+        // Hence, a 'cd' in the first loop won't affect the next loop.
+        stack.with_env(&orig_env_vars, &orig_env_hidden);
+
+        if let Some(var_id) = var_id {
+            stack.add_var(var_id, element.clone());
+        }
+
+        let key = eval_block(
+            engine_state,
+            &mut stack,
+            &block,
+            element.clone().into_pipeline_data(),
+            call.redirect_stdout,
+            call.redirect_stderr,
+        )?
+        .into_value(span);
+
+        let is_new_max = match &best {
+            Some((best_key, _)) => matches!(key.partial_cmp(best_key), Some(Ordering::Greater)),
+            None => true,
+        };
+
+        if is_new_max {
+            best = Some((key, element));
+        }
+    }
+
+    match best {
+        Some((_, element)) => Ok(element.into_pipeline_data()),
+        None => maximum(&[], span, span).map(IntoPipelineData::into_pipeline_data),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -1,10 +1,13 @@
 use crate::math::reducers::{reducer_for, Reduce};
 use crate::math::utils::run_with_function;
+use nu_engine::{eval_block, CallExt};
 use nu_protocol::ast::Call;
-use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
 use nu_protocol::{
-    record, Category, Example, PipelineData, ShellError, Signature, Span, Type, Value,
+    record, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
 };
+use std::cmp::Ordering;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -20,6 +23,12 @@ impl Command for SubCommand {
                 (Type::List(Box::new(Type::Any)), Type::Any),
                 (Type::Table(vec![]), Type::Record(vec![])),
             ])
+            .named(
+                "by",
+                SyntaxShape::Closure(None),
+                "find the element whose closure result is the smallest, rather than comparing elements directly",
+                None,
+            )
             .allow_variants_without_examples(true)
             .category(Category::Math)
     }
@@ -34,11 +43,14 @@ impl Command for SubCommand {
 
     fn run(
         &self,
-        _engine_state: &EngineState,
-        _stack: &mut Stack,
+        engine_state: &EngineState,
+        stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
+        if let Some(closure) = call.get_flag(engine_state, stack, "by")? {
+            return find_min_by(engine_state, stack, call, input, closure);
+        }
         run_with_function(call, input, minimum)
     }
 
@@ -62,6 +74,11 @@ impl Command for SubCommand {
                 example: "[-50 'hello' true] | math min",
                 result: Some(Value::test_bool(true)),
             },
+            Example {
+                description: "Find the row whose size is smallest",
+                example: "ls | math min --by {|f| $f.size }",
+                result: None,
+            },
         ]
     }
 }
@@ -71,6 +88,57 @@ pub fn minimum(values: &[Value], span: Span, head: Span) -> Result<Value, ShellE
     min_func(Value::nothing(head), values.to_vec(), span, head)
 }
 
+fn find_min_by(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+    closure: Closure,
+) -> Result<PipelineData, ShellError> {
+    let span = call.head;
+    let block = engine_state.get_block(closure.block_id).clone();
+    let mut stack = stack.captures_to_stack(closure.captures);
+    let orig_env_vars = stack.env_vars.clone();
+    let orig_env_hidden = stack.env_hidden.clone();
+    let var_id = block.signature.get_positional(0).and_then(|var| var.var_id);
+
+    let mut best: Option<(Value, Value)> = None;
+    for element in input.into_iter() {
+        // with_env() is used here to ensure that each iteration uses
+        // a different set of environment variables.
+        // Hence, a 'cd' in the first loop won't affect the next loop.
+        stack.with_env(&orig_env_vars, &orig_env_hidden);
+
+        if let Some(var_id) = var_id {
+            stack.add_var(var_id, element.clone());
+        }
+
+        let key = eval_block(
+            engine_state,
+            &mut stack,
+            &block,
+            element.clone().into_pipeline_data(),
+            call.redirect_stdout,
+            call.redirect_stderr,
+        )?
+        .into_value(span);
+
+        let is_new_min = match &best {
+            Some((best_key, _)) => matches!(key.partial_cmp(best_key), Some(Ordering::Less)),
+            None => true,
+        };
+
+        if is_new_min {
+            best = Some((key, element));
+        }
+    }
+
+    match best {
+        Some((_, element)) => Ok(element.into_pipeline_data()),
+        None => minimum(&[], span, span).map(IntoPipelineData::into_pipeline_data),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;